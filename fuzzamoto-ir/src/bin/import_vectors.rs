@@ -0,0 +1,57 @@
+//! Tool that converts a line-oriented JSON table of external block/transaction test vectors into
+//! an IR seed: a `Program` file and a matching `FullProgramContext` file, both in the same
+//! postcard format `TestCase::decode` (in `fuzzamoto-scenarios`) already consumes.
+//!
+//! Usage: `import_vectors <vectors.jsonl> <num_connections> <tip_height> <out_program> <out_context>`
+//!
+//! `tip_height` should be the real height of the target's synced chain this seed will run
+//! against, so block vectors without an explicit `height` land at a realistic height relative to
+//! it (see `importer::import_vectors`).
+
+use fuzzamoto_ir::{ProgramContext, importer};
+use rand::rngs::OsRng;
+
+fn main() -> Result<(), String> {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() != 6 {
+        return Err(format!(
+            "usage: {} <vectors.jsonl> <num_connections> <tip_height> <out_program> <out_context>",
+            args.first().map(String::as_str).unwrap_or("import_vectors")
+        ));
+    }
+
+    let vectors_file = std::fs::File::open(&args[1]).map_err(|e| e.to_string())?;
+    let vectors = importer::read_vectors(vectors_file)?;
+
+    let num_connections: usize = args[2].parse().map_err(|e: std::num::ParseIntError| e.to_string())?;
+    let tip_height: u32 = args[3].parse().map_err(|e: std::num::ParseIntError| e.to_string())?;
+    let context = ProgramContext {
+        num_nodes: 1,
+        num_connections,
+        timestamp: 0,
+    };
+
+    let (program, full_context) =
+        importer::import_vectors(&vectors, context, tip_height, &mut OsRng)?;
+
+    std::fs::write(
+        &args[4],
+        postcard::to_allocvec(&program).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+    std::fs::write(
+        &args[5],
+        postcard::to_allocvec(&full_context).map_err(|e| e.to_string())?,
+    )
+    .map_err(|e| e.to_string())?;
+
+    println!(
+        "imported {} vector(s) -> {} ({} txos, {} headers)",
+        vectors.len(),
+        args[4],
+        full_context.txos.len(),
+        full_context.headers.len()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use rand::{Rng, RngCore};
+
+use crate::{
+    Generator, GeneratorResult, Instruction, Operation, PerTestcaseMetadata, ProgramBuilder,
+};
+
+/// `CompactBlockGenerator` models the full BIP152 compact-block-relay state machine: `sendcmpct`
+/// mode negotiation, `cmpctblock` announcements with a chosen short-id/prefilled-txn split, and
+/// the `getblocktxn` -> `blocktxn` reconstruction round trip. This exercises the reconstruction
+/// path (missing/extra/duplicate indices, bad short-ids, oversized prefilled sets) that a
+/// single-shot template response never reaches.
+#[derive(Default)]
+pub struct CompactBlockGenerator;
+
+impl<R: RngCore> Generator<R> for CompactBlockGenerator {
+    fn generate(
+        &self,
+        builder: &mut ProgramBuilder,
+        rng: &mut R,
+        meta: Option<&PerTestcaseMetadata>,
+    ) -> GeneratorResult {
+        // If a peer asked us to fill in some of the short-ids from a prior `cmpctblock`
+        // announcement, react to that `getblocktxn` before doing anything else.
+        if let Some(meta) = meta
+            && !meta.block_txn_request().is_empty()
+        {
+            let insertion_point = builder.instructions.len();
+            let block_txn_req = meta.block_txn_request();
+            let choice = block_txn_req
+                .iter()
+                .position(|x| x.triggering_instruction_index == insertion_point - 1)
+                .expect("Triggering instruction not found");
+            let request = &block_txn_req[choice];
+
+            let block_txn = builder
+                .append(Instruction {
+                    inputs: vec![],
+                    operation: Operation::BuildBlockTxn {
+                        indices: request.requested_indices.clone(),
+                    },
+                })
+                .expect("Inserting BuildBlockTxn should always succeed")
+                .pop()
+                .expect("BuildBlockTxn should always produce a var");
+
+            builder
+                .append(Instruction {
+                    inputs: vec![request.connection_index, block_txn.index],
+                    operation: Operation::SendBlockTxn,
+                })
+                .expect("Inserting SendBlockTxn should always succeed");
+
+            return Ok(());
+        }
+
+        let conn_var = builder.get_or_create_random_connection(rng);
+
+        // Otherwise, either (re)negotiate the compact-block mode for this peer or announce a new
+        // block over it, whether or not `sendcmpct` was sent on it before.
+        if rng.gen_bool(0.5) {
+            let high_bandwidth = rng.gen_bool(0.5);
+            builder.force_append(
+                vec![conn_var.index],
+                Operation::SendSendcmpct { high_bandwidth },
+            );
+            return Ok(());
+        }
+
+        // Split the block's transactions between short-ids and prefilled transactions. Letting
+        // `num_prefilled` occasionally exceed the transaction count synthesizes the oversized/
+        // duplicate-index prefilled sets the reconstruction path needs to be fuzzed against.
+        let num_short_ids = rng.gen_range(0..32);
+        let num_prefilled = rng.gen_range(0..4);
+
+        let cmpct_block = builder
+            .append(Instruction {
+                inputs: vec![],
+                operation: Operation::BuildCmpctBlock {
+                    num_short_ids,
+                    num_prefilled,
+                },
+            })
+            .expect("Inserting BuildCmpctBlock should always succeed")
+            .pop()
+            .expect("BuildCmpctBlock should always produce a var");
+
+        builder
+            .append(Instruction {
+                inputs: vec![conn_var.index, cmpct_block.index],
+                operation: Operation::SendCmpctBlock,
+            })
+            .expect("Inserting SendCmpctBlock should always succeed");
+
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "CompactBlockGenerator"
+    }
+
+    fn choose_index(
+        &self,
+        program: &crate::Program,
+        rng: &mut R,
+        meta: Option<&PerTestcaseMetadata>,
+    ) -> Option<usize> {
+        if let Some(meta) = meta
+            && !meta.block_txn_request().is_empty()
+        {
+            let block_txn_req = meta.block_txn_request();
+            let choice = rng.gen_range(0..block_txn_req.len());
+            let insertion_point = block_txn_req[choice].triggering_instruction_index + 1;
+            Some(insertion_point)
+        } else {
+            program
+                .get_random_instruction_index(rng, <Self as Generator<R>>::requested_context(self))
+        }
+    }
+}
+
+impl CompactBlockGenerator {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
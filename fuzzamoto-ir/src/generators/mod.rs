@@ -0,0 +1,23 @@
+mod compact_block;
+mod gettemplate;
+mod rpc;
+mod template;
+
+pub use compact_block::CompactBlockGenerator;
+pub use gettemplate::GetTemplateGenerator;
+pub use rpc::RpcGenerator;
+pub use template::TemplateGenerator;
+
+pub use crate::{Generator, GeneratorError, GeneratorResult, ProgramBuilder};
+
+use rand::RngCore;
+
+/// The default set of generators used to build and mutate IR programs.
+pub fn default_generators<R: RngCore + 'static>() -> Vec<Box<dyn Generator<R>>> {
+    vec![
+        Box::new(GetTemplateGenerator::default()),
+        Box::new(TemplateGenerator::default()),
+        Box::new(RpcGenerator::default()),
+        Box::new(CompactBlockGenerator::default()),
+    ]
+}
@@ -0,0 +1,113 @@
+use rand::{Rng, RngCore};
+
+use crate::{
+    Instruction, Operation, PerTestcaseMetadata, VarType,
+    generators::{Generator, GeneratorError, GeneratorResult, ProgramBuilder},
+};
+
+/// How many vars a single RPC argument is synthesized from.
+#[derive(Clone, Copy)]
+enum RpcArgShape {
+    /// One IR value maps to one scalar argument, e.g. a block hash.
+    Single(VarType),
+    /// 1-3 IR values of the given kind are collected into a single JSON array argument, e.g. the
+    /// list of raw txs `submitpackage`/`testmempoolaccept` take.
+    Array(VarType),
+}
+
+/// A single entry in the RPC method table: the method name, together with the shape of each of
+/// its arguments (in order). An empty `args` list means the method is called without arguments.
+struct RpcMethod {
+    name: &'static str,
+    args: &'static [RpcArgShape],
+}
+
+/// The set of RPC methods `RpcGenerator` picks from. Each entry mirrors an RPC entry point that is
+/// interesting to fuzz alongside P2P traffic: mempool/chain introspection, single-value lookups
+/// keyed by a txid/block hash already in scope, and the package-relay/package-mempool-acceptance
+/// entry points, whose array argument is raw tx hex already produced elsewhere in the program.
+const RPC_METHODS: &[RpcMethod] = &[
+    RpcMethod {
+        name: "getmempoolinfo",
+        args: &[],
+    },
+    RpcMethod {
+        name: "getrawmempool",
+        args: &[],
+    },
+    RpcMethod {
+        name: "getblockchaininfo",
+        args: &[],
+    },
+    RpcMethod {
+        name: "getblockheader",
+        args: &[RpcArgShape::Single(VarType::BlockHash)],
+    },
+    RpcMethod {
+        name: "getmempoolentry",
+        args: &[RpcArgShape::Single(VarType::Txid)],
+    },
+    RpcMethod {
+        name: "submitpackage",
+        args: &[RpcArgShape::Array(VarType::RawTransaction)],
+    },
+    RpcMethod {
+        name: "testmempoolaccept",
+        args: &[RpcArgShape::Array(VarType::RawTransaction)],
+    },
+];
+
+/// `RpcGenerator` emits `Operation::CallRpc` instructions, picking a method from
+/// [`RPC_METHODS`] and synthesizing its arguments from IR values already in scope (txids, block
+/// hashes, raw tx hex), so RPC calls are generated, mutated and minimized like every other
+/// action instead of being spliced in from a separate byte-offset scheme.
+#[derive(Default)]
+pub struct RpcGenerator;
+
+impl<R: RngCore> Generator<R> for RpcGenerator {
+    fn generate(
+        &self,
+        builder: &mut ProgramBuilder,
+        rng: &mut R,
+        _meta: Option<&PerTestcaseMetadata>,
+    ) -> GeneratorResult {
+        let method = &RPC_METHODS[rng.gen_range(0..RPC_METHODS.len())];
+
+        // `args` records, per RPC argument, how many consecutive `inputs` entries it consumes
+        // (1 for a scalar argument, N for an array one) so the compiler can group the
+        // corresponding IR values into a single JSON array where the RPC expects one, instead of
+        // passing e.g. a `submitpackage` package as N separate scalar arguments.
+        let mut inputs = Vec::new();
+        let mut args = Vec::with_capacity(method.args.len());
+        for arg_shape in method.args {
+            let (var_type, count) = match arg_shape {
+                RpcArgShape::Single(var_type) => (*var_type, 1),
+                RpcArgShape::Array(var_type) => (*var_type, rng.gen_range(1..=3)),
+            };
+
+            for _ in 0..count {
+                let var = builder
+                    .get_or_create_random_var(rng, var_type)
+                    .ok_or_else(|| GeneratorError::InvalidContext(builder.context().clone()))?;
+                inputs.push(var.index);
+            }
+            args.push((var_type, count));
+        }
+
+        builder
+            .append(Instruction {
+                inputs,
+                operation: Operation::CallRpc {
+                    method: method.name.to_string(),
+                    args,
+                },
+            })
+            .expect("Inserting CallRpc should always succeed");
+
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "RpcGenerator"
+    }
+}
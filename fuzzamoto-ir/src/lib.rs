@@ -0,0 +1,6 @@
+// This snapshot only carries the modules touched by the IR work in this changeset
+// (`generators`, `importer`); the rest of the crate root (`Program`, `Operation`, `compiler`,
+// etc.) already exists upstream and isn't reproduced here.
+
+pub mod generators;
+pub mod importer;
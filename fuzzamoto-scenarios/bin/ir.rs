@@ -4,6 +4,9 @@ use std::time::{Duration, Instant};
 #[cfg(feature = "nyx")]
 use fuzzamoto_nyx_sys::*;
 
+#[cfg(feature = "honggfuzz")]
+use honggfuzz::fuzz;
+
 use bitcoin::hashes::Hash;
 use fuzzamoto::{
     connections::Transport,
@@ -35,62 +38,31 @@ const OP_TRUE_SCRIPT_PUBKEY: [u8; 34] = [
 /// Hybrid IR scenario that combines P2P messages with RPC calls
 struct IrScenario<TX: Transport, T: Target<TX> + ConnectableTarget> {
     inner: GenericScenario<TX, T>,
+    /// Differential reference targets (e.g. other Bitcoin Core versions/build configs) that are
+    /// kept in sync with the primary target and checked for consensus against it, paired with
+    /// the `--target` binary path each was built from so a divergence can name which binary
+    /// disagreed.
     #[cfg(any(feature = "oracle_netsplit", feature = "oracle_consensus"))]
-    second: T,
-}
-
-/// Extended compiled action that includes RPC calls
-#[derive(Debug)]
-enum HybridAction {
-    P2P(CompiledAction),
-    RpcGetMempoolInfo,
+    references: Vec<(String, T)>,
 }
 
 pub struct TestCase {
     program: CompiledProgram,
-    // Add RPC call points - indices in the action sequence where RPC should be called
-    rpc_call_points: Vec<usize>,
 }
 
 impl<'a> ScenarioInput<'a> for TestCase {
     fn decode(bytes: &'a [u8]) -> Result<Self, String> {
-        // First byte(s) determine number of RPC calls
-        if bytes.is_empty() {
-            return Err("Empty input".to_string());
-        }
-
-        let num_rpc_calls = (bytes[0] % 10) as usize; // Max 10 RPC calls
-        let mut rpc_call_points = Vec::new();
-
-        /*
-        // Workaround to have less RPC calls
-        if num_rpc_calls > 0 {
-            num_rpc_calls = 1;
-        }*/
-
-        // Next bytes determine where to insert RPC calls
-        for i in 0..num_rpc_calls {
-            if i + 1 < bytes.len() {
-                rpc_call_points.push(bytes[i + 1] as usize);
-            }
-        }
-
-        // Rest is the IR program
-        let program_bytes = &bytes[(num_rpc_calls + 1).min(bytes.len())..];
-
+        // RPC calls are now `Operation::CallRpc` entries compiled into the program's action
+        // sequence like any other action, so the whole input is just the IR program.
         let program = if cfg!(feature = "compile_in_vm") {
-            let program: Program =
-                postcard::from_bytes(program_bytes).map_err(|e| e.to_string())?;
+            let program: Program = postcard::from_bytes(bytes).map_err(|e| e.to_string())?;
             let mut compiler = Compiler::new();
             compiler.compile(&program).map_err(|e| e.to_string())?
         } else {
-            postcard::from_bytes(program_bytes).map_err(|e| e.to_string())?
+            postcard::from_bytes(bytes).map_err(|e| e.to_string())?
         };
 
-        Ok(Self {
-            program,
-            rpc_call_points,
-        })
+        Ok(Self { program })
     }
 }
 
@@ -177,6 +149,8 @@ where
             }
         }
 
+        // honggfuzz (and any other non-Nyx backend) has no snapshot host to dump the context to,
+        // so it reuses the same `DUMP_CONTEXT` file path as a plain local run.
         #[cfg(not(feature = "nyx"))]
         if let Ok(context_file) = std::env::var("DUMP_CONTEXT") {
             std::fs::write(context_file, &full_context).map_err(|e| e.to_string())?;
@@ -185,16 +159,30 @@ where
         Ok(())
     }
 
+    /// Builds every differential reference target from the `--target` paths following the
+    /// primary one (`args[2..]`), connecting and syncing each to the primary in turn. If no
+    /// additional paths were given, a single reference is built from the primary's own path so
+    /// the oracle still has something to compare against.
     #[cfg(any(feature = "oracle_netsplit", feature = "oracle_consensus"))]
-    fn create_and_sync_second_target(args: &[String], primary: &T) -> Result<T, String> {
-        let mut second = if args.len() > 2 {
-            T::from_path(&args[2])?
+    fn create_and_sync_reference_targets(
+        args: &[String],
+        primary: &T,
+    ) -> Result<Vec<(String, T)>, String> {
+        let reference_paths: Vec<&String> = if args.len() > 2 {
+            args[2..].iter().collect()
         } else {
-            T::from_path(&args[1])?
+            vec![&args[1]]
         };
-        second.connect_to(primary)?;
-        Self::sync_nodes(primary, &mut second)?;
-        Ok(second)
+
+        let mut references = Vec::with_capacity(reference_paths.len());
+        for path in reference_paths {
+            let mut reference = T::from_path(path)?;
+            reference.connect_to(primary)?;
+            Self::sync_nodes(primary, &mut reference)?;
+            references.push((path.clone(), reference));
+        }
+
+        Ok(references)
     }
 
     #[cfg(any(feature = "oracle_netsplit", feature = "oracle_consensus"))]
@@ -225,55 +213,38 @@ where
         Ok(())
     }
 
-    /// Build the hybrid action sequence by interleaving P2P and RPC actions
-    fn build_hybrid_actions(&self, testcase: &TestCase) -> Vec<HybridAction> {
-        let mut hybrid_actions = Vec::new();
-        let total_actions = testcase.program.actions.len();
-
-        // Convert P2P actions
-        for (idx, action) in testcase.program.actions.iter().enumerate() {
-            // Check if we should insert an RPC call at this position
-            if testcase.rpc_call_points.contains(&idx) {
-                hybrid_actions.push(HybridAction::RpcGetMempoolInfo);
-            }
-            hybrid_actions.push(HybridAction::P2P(action.clone()));
-        }
-
-        // Add any remaining RPC calls at the end
-        for &call_point in &testcase.rpc_call_points {
-            if call_point >= total_actions {
-                hybrid_actions.push(HybridAction::RpcGetMempoolInfo);
-            }
-        }
-
-        hybrid_actions
-    }
-
-    fn process_hybrid_actions(&mut self, actions: Vec<HybridAction>) {
+    /// Execute the compiled action sequence. RPC calls (`CompiledAction::Rpc`) are now ordinary
+    /// actions produced by the compiler like `SendRawMessage`/`SetTime`, so they are interleaved,
+    /// mutated and minimized wherever the generator/mutator placed them instead of being spliced
+    /// in from a side channel.
+    fn process_actions(&mut self, actions: &[CompiledAction]) {
         for action in actions {
             match action {
-                HybridAction::P2P(CompiledAction::SendRawMessage(from, command, message)) => {
+                CompiledAction::SendRawMessage(from, command, message) => {
                     if self.inner.connections.is_empty() {
                         continue;
                     }
 
                     let num_connections = self.inner.connections.len();
-                    if let Some(connection) = self.inner.connections.get_mut(from % num_connections)
+                    if let Some(connection) =
+                        self.inner.connections.get_mut(from % num_connections)
                     {
                         if cfg!(feature = "force_send_and_ping") {
-                            let _ = connection.send_and_ping(&(command, message));
+                            let _ = connection.send_and_ping(&(command.clone(), message.clone()));
                         } else {
-                            let _ = connection.send(&(command, message));
+                            let _ = connection.send(&(command.clone(), message.clone()));
                         }
                     }
                 }
-                HybridAction::P2P(CompiledAction::SetTime(time)) => {
-                    let _ = self.inner.target.set_mocktime(time);
+                CompiledAction::SetTime(time) => {
+                    let _ = self.inner.target.set_mocktime(*time);
                     #[cfg(any(feature = "oracle_netsplit", feature = "oracle_consensus"))]
-                    let _ = self.second.set_mocktime(time);
+                    for (_, reference) in self.references.iter_mut() {
+                        let _ = reference.set_mocktime(*time);
+                    }
                 }
-                HybridAction::RpcGetMempoolInfo => {
-                    let _ = self.inner.target.call_rpc("getmempoolinfo", &[]);
+                CompiledAction::Rpc(method, args) => {
+                    let _ = self.inner.target.call_rpc(method, &args[..]);
                 }
                 _ => {}
             }
@@ -295,28 +266,35 @@ where
         #[cfg(feature = "oracle_netsplit")]
         {
             let net_split_oracle = NetSplitOracle::<TX, TX>::default();
-            if let OracleResult::Fail(e) = net_split_oracle.evaluate(&NetSplitContext {
-                primary: &self.inner.target,
-                reference: &self.second,
-            }) {
-                return ScenarioResult::Fail(format!("{}", e));
+            for (path, reference) in self.references.iter() {
+                if let OracleResult::Fail(e) = net_split_oracle.evaluate(&NetSplitContext {
+                    primary: &self.inner.target,
+                    reference,
+                }) {
+                    return ScenarioResult::Fail(format!("{}: {}", path, e));
+                }
             }
         }
 
         #[cfg(feature = "oracle_consensus")]
         {
-            if !self.second.is_connected_to(&self.inner.target) {
-                let _ = self.second.connect_to(&self.inner.target);
-            }
-
             let consensus_oracle = ConsensusOracle::<TX, TX>::default();
-            if let OracleResult::Fail(e) = consensus_oracle.evaluate(&ConsensusContext {
-                primary: &self.inner.target,
-                reference: &self.second,
-                consensus_timeout: Duration::from_secs(60),
-                poll_interval: Duration::from_millis(10),
-            }) {
-                return ScenarioResult::Fail(format!("{}", e));
+            for (path, reference) in self.references.iter_mut() {
+                if !reference.is_connected_to(&self.inner.target) {
+                    let _ = reference.connect_to(&self.inner.target);
+                }
+
+                if let OracleResult::Fail(e) = consensus_oracle.evaluate(&ConsensusContext {
+                    primary: &self.inner.target,
+                    reference,
+                    consensus_timeout: Duration::from_secs(60),
+                    poll_interval: Duration::from_millis(10),
+                }) {
+                    return ScenarioResult::Fail(format!(
+                        "primary tip diverged from reference binary {}: {}",
+                        path, e
+                    ));
+                }
             }
         }
 
@@ -341,24 +319,51 @@ where
         Self::dump_context(context, txos, headers)?;
 
         #[cfg(any(feature = "oracle_netsplit", feature = "oracle_consensus"))]
-        let second = Self::create_and_sync_second_target(args, &inner.target)?;
+        let references = Self::create_and_sync_reference_targets(args, &inner.target)?;
 
         Ok(Self {
             inner,
             #[cfg(any(feature = "oracle_netsplit", feature = "oracle_consensus"))]
-            second,
+            references,
         })
     }
 
     fn run(&mut self, testcase: TestCase) -> ScenarioResult {
-        let hybrid_actions = self.build_hybrid_actions(&testcase);
-        self.process_hybrid_actions(hybrid_actions);
+        self.process_actions(&testcase.program.actions);
         self.ping_connections();
         self.evaluate_oracles()
     }
 }
 
+#[cfg(not(feature = "honggfuzz"))]
 fuzzamoto_main!(
     IrScenario::<fuzzamoto::connections::V1Transport, BitcoinCoreTarget>,
     TestCase
 );
+
+/// honggfuzz persistent-mode entry point: unlike the Nyx snapshot runner, there is no hypervisor
+/// to restore process state between inputs, so a single long-lived target is kept running for
+/// the whole fuzzing session and every input is replayed against it in a loop. Each iteration
+/// reads one testcase from honggfuzz's input buffer, decodes it the same way the Nyx/plain
+/// backends do, and runs it against the scenario; a panic inside the closure is caught by
+/// honggfuzz's own panic hook and reported through its usual crash protocol. The context dump
+/// still goes through the `DUMP_CONTEXT` file path used by every non-Nyx backend.
+#[cfg(feature = "honggfuzz")]
+fn main() {
+    env_logger::init();
+    let args: Vec<String> = std::env::args().collect();
+
+    let mut scenario =
+        IrScenario::<fuzzamoto::connections::V1Transport, BitcoinCoreTarget>::new(&args)
+            .expect("failed to set up scenario");
+
+    loop {
+        fuzz!(|data: &[u8]| {
+            if let Ok(testcase) = TestCase::decode(data) {
+                if let ScenarioResult::Fail(reason) = scenario.run(testcase) {
+                    panic!("scenario failed: {}", reason);
+                }
+            }
+        });
+    }
+}